@@ -1,4 +1,5 @@
 mod globals;
+mod presets;
 mod resources;
 mod ui;
 
@@ -6,7 +7,10 @@ use crate::globals::*;
 use crate::ui::UiPlugin;
 use bevy::prelude::*;
 use rand::prelude::*;
-use crate::resources::grid::GridSettings;
+use serde::{Deserialize, Serialize};
+use crate::resources::grid::{BoundaryMode, GridSettings};
+use crate::resources::simulation::SimulationSettings;
+use crate::resources::spatial_grid::SpatialGrid;
 
 #[derive(Resource, Default)]
 struct MouseWorldPosition {
@@ -16,8 +20,30 @@ struct MouseWorldPosition {
 #[derive(Component, Default)]
 struct Velocity(Vec2);
 
+// Position/rotation as simulated by `FixedUpdate`, kept apart from the
+// rendered `Transform` so the latter can be interpolated between the
+// previous and current fixed steps for smooth motion at any frame rate.
+#[derive(Component, Default)]
+struct SimTransform {
+    previous_translation: Vec2,
+    current_translation: Vec2,
+    previous_rotation: Quat,
+    current_rotation: Quat,
+}
+
+impl SimTransform {
+    fn at(translation: Vec2) -> Self {
+        Self {
+            previous_translation: translation,
+            current_translation: translation,
+            previous_rotation: Quat::IDENTITY,
+            current_rotation: Quat::IDENTITY,
+        }
+    }
+}
+
 #[derive(Component)]
-#[require(Transform, Velocity)]
+#[require(Transform, Velocity, SimTransform)]
 struct Boid {
     mass: f32,
     max_speed: f32,
@@ -34,7 +60,17 @@ impl Default for Boid {
     }
 }
 
-#[derive(Resource)]
+impl Boid {
+    // Predators hunt prey, so they need a speed edge or they'd never catch up.
+    fn predator() -> Self {
+        Self {
+            max_speed: BOID_MAX_SPEED * 1.3,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Resource, Serialize, Deserialize, Clone)]
 struct BoidSettings {
     separation_radius: f32,
     alignment_radius: f32,
@@ -45,6 +81,13 @@ struct BoidSettings {
     view_angle: f32,
     mouse_attraction_weight: f32,
     mouse_arrival_radius: f32,
+    detection_length: f32,
+    avoidance_strength: f32,
+    predator_count: usize,
+    predator_detection_radius: f32,
+    predator_arrival_radius: f32,
+    flee_radius: f32,
+    flee_weight: f32,
 }
 
 impl Default for BoidSettings {
@@ -58,28 +101,64 @@ impl Default for BoidSettings {
             alignment_weight: 1.8,      
             cohesion_weight: 1.0,       
 
-            view_angle: 4.7,            
+            view_angle: 4.7,
             mouse_attraction_weight: 0.1,
             mouse_arrival_radius: 30.0,
+
+            detection_length: 60.0,
+            avoidance_strength: 400.0,
+
+            predator_count: 5,
+            predator_detection_radius: 200.0,
+            predator_arrival_radius: 40.0,
+            flee_radius: 120.0,
+            flee_weight: 2.0,
         }
     }
 }
 
-// Fonction utilitaire pour vérifier si un boid est dans le champ de vision
-fn is_in_view(boid_pos: Vec2, boid_dir: Vec2, other_pos: Vec2, view_angle: f32) -> bool {
-    let to_other = (other_pos - boid_pos).normalize_or_zero();
+#[derive(Component)]
+struct Obstacle {
+    center: Vec2,
+    radius: f32,
+}
+
+#[derive(Component, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Species {
+    Prey,
+    Predator,
+}
+
+// Fonction utilitaire pour vérifier si un boid est dans le champ de vision.
+// `to_other` est déjà le vecteur (éventuellement torique) vers le voisin.
+fn is_in_view(boid_dir: Vec2, to_other: Vec2, view_angle: f32) -> bool {
     let angle = boid_dir.dot(to_other).acos();
     angle <= view_angle / 2.0
 }
 
+/// Plus court déplacement entre deux points sur un tore de taille `width`x`height`.
+fn toroidal_delta(delta: Vec2, width: f32, height: f32) -> Vec2 {
+    Vec2::new(
+        delta.x - width * (delta.x / width).round(),
+        delta.y - height * (delta.y / height).round(),
+    )
+}
+
+/// Ramène `value` dans `[-half_extent, half_extent]` en la faisant réapparaître
+/// de l'autre côté (mode `BoundaryMode::Wrap`).
+fn wrap_coordinate(value: f32, half_extent: f32) -> f32 {
+    let size = half_extent * 2.0;
+    (value + half_extent).rem_euclid(size) - half_extent
+}
+
 // Structure pour stocker les voisins d'un boid
 struct Neighbors {
-    separation: Vec<(Vec2, Vec2)>, // (position, vélocité)
-    alignment: Vec<(Vec2, Vec2)>,
-    cohesion: Vec<(Vec2, Vec2)>,
+    separation: Vec<(Vec2, Vec2)>, // (delta vers le voisin, vélocité)
+    alignment: Vec<(Vec2, Vec2)>,  // (position, vélocité)
+    cohesion: Vec<(Vec2, Vec2)>,   // (delta vers le voisin, vélocité)
 }
 
-fn calculate_separation(boid_pos: Vec2, neighbors: &[(Vec2, Vec2)]) -> Vec2 {
+fn calculate_separation(neighbors: &[(Vec2, Vec2)]) -> Vec2 {
     if neighbors.is_empty() {
         return Vec2::ZERO;
     }
@@ -88,12 +167,11 @@ fn calculate_separation(boid_pos: Vec2, neighbors: &[(Vec2, Vec2)]) -> Vec2 {
     // Ici, on additionne tous les vecteurs de répulsion
     let steer = neighbors
         .iter()
-        .fold(Vec2::ZERO, |acc, &(neighbor_pos, _)| {
-            let diff = boid_pos - neighbor_pos;
-            let distance = diff.length();
+        .fold(Vec2::ZERO, |acc, &(delta_to_neighbor, _)| {
+            let distance = delta_to_neighbor.length();
             // Plus le voisin est proche, plus la répulsion est forte
             if distance > 0.0 {
-                acc + diff.normalize() / distance
+                acc - delta_to_neighbor.normalize() / distance
             } else {
                 acc
             }
@@ -117,30 +195,101 @@ fn calculate_alignment(boid_velocity: Vec2, neighbors: &[(Vec2, Vec2)]) -> Vec2
     (avg_velocity - boid_velocity).normalize_or_zero()
 }
 
-fn calculate_cohesion(boid_pos: Vec2, neighbors: &[(Vec2, Vec2)]) -> Vec2 {
+fn calculate_cohesion(neighbors: &[(Vec2, Vec2)]) -> Vec2 {
     if neighbors.is_empty() {
         return Vec2::ZERO;
     }
 
-    // Centre de masse du groupe
-    let center = neighbors.iter().map(|&(pos, _)| pos).sum::<Vec2>() / neighbors.len() as f32;
+    // Direction moyenne vers le centre de masse du groupe
+    let avg_delta_to_center =
+        neighbors.iter().map(|&(delta, _)| delta).sum::<Vec2>() / neighbors.len() as f32;
+
+    avg_delta_to_center.normalize_or_zero()
+}
+
+// Évitement d'obstacles façon "feeler" : on projette deux points devant le
+// boid et on retient l'obstacle le plus menaçant parmi ceux qu'ils touchent.
+fn calculate_obstacle_avoidance(
+    pos: Vec2,
+    vel: Vec2,
+    obstacles: &[(Vec2, f32)],
+    detection_length: f32,
+    avoidance_strength: f32,
+) -> Vec2 {
+    let direction = vel.normalize_or_zero();
+    if direction == Vec2::ZERO {
+        return Vec2::ZERO;
+    }
+
+    let ahead = pos + direction * detection_length;
+    let ahead2 = pos + direction * detection_length * 0.5;
+
+    let mut most_threatening: Option<(Vec2, f32, f32)> = None; // (center, radius, distance)
+
+    for &(center, radius) in obstacles {
+        let threat_radius = radius + BOID_SIZE;
+        let hits = center.distance(pos) < threat_radius
+            || center.distance(ahead) < threat_radius
+            || center.distance(ahead2) < threat_radius;
+
+        if !hits {
+            continue;
+        }
+
+        let distance = center.distance(pos);
+        if most_threatening.is_none_or(|(_, _, best)| distance < best) {
+            most_threatening = Some((center, radius, distance));
+        }
+    }
 
-    (center - boid_pos).normalize_or_zero()
+    match most_threatening {
+        Some((center, radius, distance)) if distance < radius => {
+            // Déjà dans l'obstacle : on pousse droit vers l'extérieur.
+            (pos - center).normalize_or_zero() * avoidance_strength
+        }
+        Some((center, _, _)) => (ahead - center).normalize_or_zero() * avoidance_strength,
+        None => Vec2::ZERO,
+    }
+}
+
+fn update_spatial_grid(
+    mut spatial_grid: ResMut<SpatialGrid>,
+    settings: Res<BoidSettings>,
+    grid_settings: Res<GridSettings>,
+    boids: Query<(Entity, &SimTransform, &Velocity, &Species), With<Boid>>,
+) {
+    let cell_size = settings
+        .separation_radius
+        .max(settings.alignment_radius)
+        .max(settings.cohesion_radius)
+        .max(settings.predator_detection_radius)
+        .max(settings.flee_radius);
+    spatial_grid.clear(cell_size);
+
+    for (entity, sim_transform, velocity, species) in boids.iter() {
+        spatial_grid.insert(
+            entity,
+            sim_transform.current_translation,
+            velocity.0,
+            *species,
+            &grid_settings,
+        );
+    }
 }
 
 fn boid_movement_system(
-    mut boids: Query<(Entity, &mut Transform, &mut Velocity, &Boid)>,
+    mut boids: Query<(Entity, &mut SimTransform, &mut Velocity, &Boid, &Species)>,
     settings: Res<BoidSettings>,
+    grid_settings: Res<GridSettings>,
+    spatial_grid: Res<SpatialGrid>,
+    obstacles: Query<&Obstacle>,
     mouse_pos: Res<MouseWorldPosition>,
     time: Res<Time>,
 ) {
-    let boid_data: Vec<_> = boids
-        .iter()
-        .map(|(entity, transform, velocity, _)| (entity, transform.translation.xy(), velocity.0))
-        .collect();
+    let obstacle_data: Vec<(Vec2, f32)> = obstacles.iter().map(|o| (o.center, o.radius)).collect();
 
-    for (entity, mut transform, mut velocity, boid) in boids.iter_mut() {
-        let pos = transform.translation.xy();
+    for (entity, mut sim, mut velocity, boid, species) in boids.iter_mut() {
+        let pos = sim.current_translation;
         let vel = velocity.0;
         let direction = vel.normalize_or_zero();
 
@@ -150,32 +299,58 @@ fn boid_movement_system(
             alignment: Vec::new(),
             cohesion: Vec::new(),
         };
+        let mut nearest_opposite: Option<(Vec2, f32)> = None;
 
-        for &(other_entity, other_pos, other_vel) in boid_data.iter() {
+        let cell = spatial_grid.cell_of(pos, &grid_settings);
+        for &(other_entity, other_pos, other_vel, other_species) in
+            spatial_grid.neighbors_of(cell, &grid_settings)
+        {
             if entity == other_entity {
                 continue;
             }
 
-            let distance = pos.distance(other_pos);
-            if !is_in_view(pos, direction, other_pos, settings.view_angle) {
+            let raw_delta = other_pos - pos;
+            let delta = if grid_settings.boundary_mode == BoundaryMode::Wrap {
+                toroidal_delta(raw_delta, grid_settings.width, grid_settings.height)
+            } else {
+                raw_delta
+            };
+            let distance = delta.length();
+
+            if other_species != *species {
+                // La fuite doit toujours couvrir `flee_radius`, même si
+                // `predator_detection_radius` est réglé plus bas.
+                let detection_radius = settings
+                    .predator_detection_radius
+                    .max(settings.flee_radius);
+                if distance < detection_radius
+                    && nearest_opposite.is_none_or(|(_, best)| distance < best)
+                {
+                    // Position virtuelle la plus proche, correcte même à travers la couture.
+                    nearest_opposite = Some((pos + delta, distance));
+                }
+                continue;
+            }
+
+            let to_other = delta.normalize_or_zero();
+            if !is_in_view(direction, to_other, settings.view_angle) {
                 continue;
             }
 
             if distance < settings.separation_radius {
-                neighbors.separation.push((other_pos, other_vel));
+                neighbors.separation.push((delta, other_vel));
             }
             if distance < settings.alignment_radius {
                 neighbors.alignment.push((other_pos, other_vel));
             }
             if distance < settings.cohesion_radius {
-                neighbors.cohesion.push((other_pos, other_vel));
+                neighbors.cohesion.push((delta, other_vel));
             }
         }
 
-        let separation =
-            calculate_separation(pos, &neighbors.separation) * settings.separation_weight;
+        let separation = calculate_separation(&neighbors.separation) * settings.separation_weight;
         let alignment = calculate_alignment(vel, &neighbors.alignment) * settings.alignment_weight;
-        let cohesion = calculate_cohesion(pos, &neighbors.cohesion) * settings.cohesion_weight;
+        let cohesion = calculate_cohesion(&neighbors.cohesion) * settings.cohesion_weight;
 
         // NOUVEAU : Comportement "goal seeking" avec arrivée
         let mouse_seeking = if let Some(mouse_world_pos) = mouse_pos.position {
@@ -191,8 +366,43 @@ fn boid_movement_system(
             Vec2::ZERO
         };
 
+        let avoidance = calculate_obstacle_avoidance(
+            pos,
+            vel,
+            &obstacle_data,
+            settings.detection_length,
+            settings.avoidance_strength,
+        );
+
+        // Prédateurs : poursuite de la proie la plus proche. Proies : fuite
+        // du prédateur le plus proche, paniquant d'autant plus qu'il est près.
+        let predator_prey_force = match (species, nearest_opposite) {
+            (Species::Predator, Some((prey_pos, _))) => calculate_seek_with_arrival(
+                pos,
+                vel,
+                prey_pos,
+                boid.max_speed,
+                boid.max_force,
+                settings.predator_arrival_radius,
+            ),
+            (Species::Prey, Some((predator_pos, distance))) if distance < settings.flee_radius => {
+                let seek = calculate_seek_with_arrival(
+                    pos,
+                    vel,
+                    predator_pos,
+                    boid.max_speed,
+                    boid.max_force,
+                    settings.predator_arrival_radius,
+                );
+                let panic_scale = 1.0 - (distance / settings.flee_radius);
+                -seek * settings.flee_weight * (1.0 + panic_scale)
+            }
+            _ => Vec2::ZERO,
+        };
+
         // Combiner toutes les forces
-        let steering_force = separation + alignment + cohesion + mouse_seeking;
+        let steering_force =
+            separation + alignment + cohesion + mouse_seeking + avoidance + predator_prey_force;
 
         // Limiter la force totale avant de l'appliquer
         let clamped_force = steering_force.clamp_length_max(boid.max_force);
@@ -202,63 +412,106 @@ fn boid_movement_system(
         velocity.0 += acceleration * time.delta_secs();
         velocity.0 = velocity.0.clamp_length_max(boid.max_speed);
 
-        // Mettre à jour position et rotation
-        transform.translation += velocity.0.extend(0.0) * time.delta_secs();
+        // Mettre à jour position et rotation de la simulation (le `Transform`
+        // rendu est interpolé à partir de ces valeurs dans `Update`).
+        sim.previous_translation = sim.current_translation;
+        sim.current_translation += velocity.0 * time.delta_secs();
+
+        if grid_settings.boundary_mode == BoundaryMode::Wrap {
+            let half_width = grid_settings.width / 2.0;
+            let half_height = grid_settings.height / 2.0;
+            sim.current_translation.x = wrap_coordinate(sim.current_translation.x, half_width);
+            sim.current_translation.y = wrap_coordinate(sim.current_translation.y, half_height);
+        }
 
         if velocity.0.length() > 0.0 {
             let angle = velocity.0.y.atan2(velocity.0.x) - std::f32::consts::FRAC_PI_2;
-            transform.rotation = Quat::from_rotation_z(angle);
+            sim.previous_rotation = sim.current_rotation;
+            sim.current_rotation = Quat::from_rotation_z(angle);
+        } else {
+            sim.previous_rotation = sim.current_rotation;
         }
     }
 }
 
+/// Interpolates the rendered `Transform` between the previous and current
+/// fixed-step simulation states so motion stays smooth regardless of the
+/// render frame rate.
+fn interpolate_transforms(
+    fixed_time: Res<Time<Fixed>>,
+    mut boids: Query<(&SimTransform, &mut Transform), With<Boid>>,
+) {
+    let alpha = fixed_time.overstep_fraction();
+    for (sim, mut transform) in boids.iter_mut() {
+        let position = sim
+            .previous_translation
+            .lerp(sim.current_translation, alpha);
+        transform.translation = position.extend(transform.translation.z);
+        transform.rotation = sim.previous_rotation.slerp(sim.current_rotation, alpha);
+    }
+}
+
+fn apply_simulation_hz(settings: Res<SimulationSettings>, mut fixed_time: ResMut<Time<Fixed>>) {
+    if settings.is_changed() {
+        fixed_time.set_timestep_hz(settings.simulation_hz as f64);
+    }
+}
+
 fn border_repulsion_system(
-    mut boids: Query<(&Transform, &mut Velocity), With<Boid>>,
+    mut boids: Query<(&mut SimTransform, &mut Velocity), With<Boid>>,
     grid_settings: Res<GridSettings>,
     time: Res<Time>,
 ) {
-    let border_distance = 50.0; // Distance à partir de laquelle la répulsion commence
-    let repulsion_strength = 200.0; // Force de la répulsion
-
-    for (transform, mut velocity) in boids.iter_mut() {
-        let pos = transform.translation.xy();
-        let mut repulsion_force = Vec2::ZERO;
-
-        // Calcul de la distance aux bords
-        let half_width = grid_settings.width / 2.0;
-        let half_height = grid_settings.height / 2.0;
-
-        // Répulsion du bord droit
-        let dist_right = half_width - pos.x;
-        if dist_right < border_distance {
-            // Plus on est proche du bord, plus la force est grande
-            let strength = (1.0 - dist_right / border_distance) * repulsion_strength;
-            repulsion_force.x -= strength;
-        }
-
-        // Répulsion du bord gauche
-        let dist_left = pos.x + half_width;
-        if dist_left < border_distance {
-            let strength = (1.0 - dist_left / border_distance) * repulsion_strength;
-            repulsion_force.x += strength;
-        }
-
-        // Répulsion du bord haut
-        let dist_top = half_height - pos.y;
-        if dist_top < border_distance {
-            let strength = (1.0 - dist_top / border_distance) * repulsion_strength;
-            repulsion_force.y -= strength;
-        }
-
-        // Répulsion du bord bas
-        let dist_bottom = pos.y + half_height;
-        if dist_bottom < border_distance {
-            let strength = (1.0 - dist_bottom / border_distance) * repulsion_strength;
-            repulsion_force.y += strength;
+    let half_width = grid_settings.width / 2.0;
+    let half_height = grid_settings.height / 2.0;
+    let border_distance = grid_settings.border_distance;
+    let repulsion_strength = grid_settings.repulsion_strength;
+
+    for (mut sim, mut velocity) in boids.iter_mut() {
+        match grid_settings.boundary_mode {
+            // La traversée des bords est déjà gérée dans boid_movement_system.
+            BoundaryMode::Wrap => {}
+            BoundaryMode::HardClamp => {
+                sim.current_translation.x = sim.current_translation.x.clamp(-half_width, half_width);
+                sim.current_translation.y = sim.current_translation.y.clamp(-half_height, half_height);
+            }
+            BoundaryMode::Repulsion => {
+                let pos = sim.current_translation;
+                let mut repulsion_force = Vec2::ZERO;
+
+                // Répulsion du bord droit
+                let dist_right = half_width - pos.x;
+                if dist_right < border_distance {
+                    // Plus on est proche du bord, plus la force est grande
+                    let strength = (1.0 - dist_right / border_distance) * repulsion_strength;
+                    repulsion_force.x -= strength;
+                }
+
+                // Répulsion du bord gauche
+                let dist_left = pos.x + half_width;
+                if dist_left < border_distance {
+                    let strength = (1.0 - dist_left / border_distance) * repulsion_strength;
+                    repulsion_force.x += strength;
+                }
+
+                // Répulsion du bord haut
+                let dist_top = half_height - pos.y;
+                if dist_top < border_distance {
+                    let strength = (1.0 - dist_top / border_distance) * repulsion_strength;
+                    repulsion_force.y -= strength;
+                }
+
+                // Répulsion du bord bas
+                let dist_bottom = pos.y + half_height;
+                if dist_bottom < border_distance {
+                    let strength = (1.0 - dist_bottom / border_distance) * repulsion_strength;
+                    repulsion_force.y += strength;
+                }
+
+                // Appliquer la force de répulsion
+                velocity.0 += repulsion_force * time.delta_secs();
+            }
         }
-
-        // Appliquer la force de répulsion
-        velocity.0 += repulsion_force * time.delta_secs();
     }
 }
 
@@ -267,6 +520,7 @@ fn generate_boids(
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
     grid_settings: Res<GridSettings>,
+    settings: Res<BoidSettings>,
 ) {
     let mut rng = rand::rng();
 
@@ -276,19 +530,30 @@ fn generate_boids(
         Vec2::new(BOID_SIZE, -BOID_SIZE),
     ));
 
-    let color = Color::WHITE;
-
-    for _ in 0..NUMBER_BOIDS {
+    for i in 0..NUMBER_BOIDS {
         let angle = rng.random::<f32>() * std::f32::consts::TAU;
         let initial_velocity = Vec2::new(angle.cos(), angle.sin()) * 80.0;
 
+        let species = if i < settings.predator_count {
+            Species::Predator
+        } else {
+            Species::Prey
+        };
+        let (boid, color) = match species {
+            Species::Predator => (Boid::predator(), Color::srgb(1.0, 0.3, 0.3)),
+            Species::Prey => (Boid::default(), Color::WHITE),
+        };
+
+        let position = Vec2::new(
+            rng.random_range(-grid_settings.width / 2.0..grid_settings.width / 2.0),
+            rng.random_range(-grid_settings.height / 2.0..grid_settings.height / 2.0),
+        );
+
         commands.spawn((
-            Boid::default(),
-            Transform::from_xyz(
-                rng.random_range(-grid_settings.width / 2.0..grid_settings.width / 2.0),
-                rng.random_range(-grid_settings.height / 2.0..grid_settings.height / 2.0),
-                0.0,
-            ),
+            boid,
+            species,
+            Transform::from_xyz(position.x, position.y, 0.0),
+            SimTransform::at(position),
             Velocity(initial_velocity),
             Mesh2d(mesh.clone()),
             MeshMaterial2d(materials.add(color)),
@@ -296,6 +561,33 @@ fn generate_boids(
     }
 }
 
+fn generate_obstacles(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    grid_settings: Res<GridSettings>,
+) {
+    let mut rng = rand::rng();
+    const OBSTACLE_COUNT: usize = 5;
+    const MIN_RADIUS: f32 = 20.0;
+    const MAX_RADIUS: f32 = 50.0;
+
+    for _ in 0..OBSTACLE_COUNT {
+        let radius = rng.random_range(MIN_RADIUS..MAX_RADIUS);
+        let center = Vec2::new(
+            rng.random_range(-grid_settings.width / 2.0..grid_settings.width / 2.0),
+            rng.random_range(-grid_settings.height / 2.0..grid_settings.height / 2.0),
+        );
+
+        commands.spawn((
+            Obstacle { center, radius },
+            Mesh2d(meshes.add(Circle::new(radius))),
+            MeshMaterial2d(materials.add(Color::srgb(0.4, 0.4, 0.4))),
+            Transform::from_xyz(center.x, center.y, 0.5),
+        ));
+    }
+}
+
 fn calculate_seek_with_arrival(
     current_pos: Vec2,
     current_vel: Vec2,
@@ -394,8 +686,16 @@ fn main() {
         .init_resource::<GridSettings>()
         .init_resource::<BoidSettings>()
         .init_resource::<MouseWorldPosition>()
-        .add_systems(Startup, (setup, generate_boids, setup_mouse_indicator))
-        .add_systems(Update, (update_mouse_position,
-                              update_mouse_indicator, boid_movement_system, border_repulsion_system).chain())
+        .init_resource::<SpatialGrid>()
+        .init_resource::<SimulationSettings>()
+        .insert_resource(Time::<Fixed>::from_hz(60.0))
+        .add_systems(Startup, (setup, generate_boids, generate_obstacles, setup_mouse_indicator))
+        .add_systems(Update, (update_mouse_position, update_mouse_indicator).chain())
+        .add_systems(Update, apply_simulation_hz)
+        .add_systems(Update, interpolate_transforms)
+        .add_systems(
+            FixedUpdate,
+            (update_spatial_grid, boid_movement_system, border_repulsion_system).chain(),
+        )
         .run();
 }
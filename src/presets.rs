@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+use crate::resources::grid::GridSettings;
+use crate::BoidSettings;
+
+/// Default location Save/Load in the UI reads from and writes to.
+pub const PRESET_PATH: &str = "flock_preset.ron";
+
+/// A named, serializable snapshot of the tunable flock parameters.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct FlockPreset {
+    pub name: String,
+    pub boid_settings: BoidSettings,
+    pub grid_settings: GridSettings,
+}
+
+pub fn save_preset(preset: &FlockPreset, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let serialized = ron::ser::to_string_pretty(preset, ron::ser::PrettyConfig::default())?;
+    fs::write(path, serialized)?;
+    Ok(())
+}
+
+pub fn load_preset(path: &str) -> Result<FlockPreset, Box<dyn std::error::Error>> {
+    let contents = fs::read_to_string(path)?;
+    Ok(ron::from_str(&contents)?)
+}
+
+pub fn built_in_presets() -> Vec<FlockPreset> {
+    vec![
+        FlockPreset {
+            name: "Tight Schooling".to_string(),
+            boid_settings: BoidSettings {
+                separation_radius: 15.0,
+                alignment_radius: 60.0,
+                cohesion_radius: 70.0,
+                separation_weight: 2.0,
+                alignment_weight: 2.5,
+                cohesion_weight: 2.0,
+                view_angle: 5.5,
+                ..Default::default()
+            },
+            grid_settings: GridSettings::default(),
+        },
+        FlockPreset {
+            name: "Loose Swarm".to_string(),
+            boid_settings: BoidSettings {
+                separation_radius: 30.0,
+                alignment_radius: 50.0,
+                cohesion_radius: 250.0,
+                separation_weight: 1.2,
+                alignment_weight: 0.8,
+                cohesion_weight: 0.5,
+                view_angle: 3.0,
+                ..Default::default()
+            },
+            grid_settings: GridSettings::default(),
+        },
+        FlockPreset {
+            name: "Predator Panic".to_string(),
+            boid_settings: BoidSettings {
+                predator_count: 8,
+                flee_radius: 150.0,
+                flee_weight: 4.0,
+                ..Default::default()
+            },
+            grid_settings: GridSettings::default(),
+        },
+    ]
+}
@@ -1,10 +1,26 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 use crate::globals::*;
 
-#[derive(Resource)]
+/// How boids react when they reach the edge of the simulation area.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum BoundaryMode {
+    /// Steer away from the border the closer a boid gets to it.
+    #[default]
+    Repulsion,
+    /// Exiting one edge reappears on the opposite side (toroidal world).
+    Wrap,
+    /// Position is clamped to stay within the border.
+    HardClamp,
+}
+
+#[derive(Resource, Serialize, Deserialize, Clone)]
 pub struct GridSettings {
     pub width: f32,
     pub height: f32,
+    pub boundary_mode: BoundaryMode,
+    pub border_distance: f32,
+    pub repulsion_strength: f32,
 }
 
 impl Default for GridSettings {
@@ -12,6 +28,9 @@ impl Default for GridSettings {
         Self {
             width: GRID_WIDTH,
             height: GRID_HEIGHT,
+            boundary_mode: BoundaryMode::default(),
+            border_distance: 50.0,
+            repulsion_strength: 200.0,
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,3 @@
+pub mod grid;
+pub mod simulation;
+pub mod spatial_grid;
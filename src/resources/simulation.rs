@@ -0,0 +1,12 @@
+use bevy::prelude::*;
+
+#[derive(Resource)]
+pub struct SimulationSettings {
+    pub simulation_hz: f32,
+}
+
+impl Default for SimulationSettings {
+    fn default() -> Self {
+        Self { simulation_hz: 60.0 }
+    }
+}
@@ -0,0 +1,71 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::resources::grid::{BoundaryMode, GridSettings};
+use crate::Species;
+
+/// Buckets boid positions/velocities by cell so each boid only has to scan
+/// its own cell plus its 8 neighbors instead of the whole flock.
+#[derive(Resource, Default)]
+pub struct SpatialGrid {
+    cell_size: f32,
+    cells: HashMap<(i32, i32), Vec<(Entity, Vec2, Vec2, Species)>>,
+}
+
+impl SpatialGrid {
+    /// Empties the grid and sets the cell size used for this frame's rebuild.
+    pub fn clear(&mut self, cell_size: f32) {
+        self.cell_size = cell_size.max(1.0);
+        self.cells.clear();
+    }
+
+    pub fn insert(
+        &mut self,
+        entity: Entity,
+        pos: Vec2,
+        vel: Vec2,
+        species: Species,
+        grid_settings: &GridSettings,
+    ) {
+        let cell = self.cell_of(pos, grid_settings);
+        self.cells
+            .entry(cell)
+            .or_default()
+            .push((entity, pos, vel, species));
+    }
+
+    pub fn cell_of(&self, pos: Vec2, grid_settings: &GridSettings) -> (i32, i32) {
+        let half_width = grid_settings.width / 2.0;
+        let half_height = grid_settings.height / 2.0;
+        (
+            ((pos.x + half_width) / self.cell_size).floor() as i32,
+            ((pos.y + half_height) / self.cell_size).floor() as i32,
+        )
+    }
+
+    /// Every boid bucketed into `cell` or one of its 8 adjacent cells. Under
+    /// `BoundaryMode::Wrap` the adjacent cell coordinates wrap around the grid
+    /// too, so a boid near one edge still sees neighbors bucketed near the
+    /// opposite edge instead of losing them off the numeric end of the axis.
+    pub fn neighbors_of<'a>(
+        &'a self,
+        cell: (i32, i32),
+        grid_settings: &GridSettings,
+    ) -> impl Iterator<Item = &'a (Entity, Vec2, Vec2, Species)> {
+        let wrap = grid_settings.boundary_mode == BoundaryMode::Wrap;
+        let cells_x = (grid_settings.width / self.cell_size).ceil().max(1.0) as i32;
+        let cells_y = (grid_settings.height / self.cell_size).ceil().max(1.0) as i32;
+
+        (-1..=1)
+            .flat_map(move |dx| (-1..=1).map(move |dy| (cell.0 + dx, cell.1 + dy)))
+            .map(move |(cx, cy)| {
+                if wrap {
+                    (cx.rem_euclid(cells_x), cy.rem_euclid(cells_y))
+                } else {
+                    (cx, cy)
+                }
+            })
+            .filter_map(move |c| self.cells.get(&c))
+            .flatten()
+    }
+}
@@ -1,7 +1,24 @@
 use bevy::prelude::*;
 use bevy_egui::*;
 use crate::BoidSettings;
-use crate::resources::grid::GridSettings;
+use crate::globals::NUMBER_BOIDS;
+use crate::presets::{self, FlockPreset};
+use crate::resources::grid::{BoundaryMode, GridSettings};
+use crate::resources::simulation::SimulationSettings;
+
+fn boundary_mode_label(mode: BoundaryMode) -> &'static str {
+    match mode {
+        BoundaryMode::Repulsion => "Repulsion",
+        BoundaryMode::Wrap => "Wrap (torus)",
+        BoundaryMode::HardClamp => "Hard Clamp",
+    }
+}
+
+// État de la fenêtre UI : quel preset intégré est sélectionné dans la liste.
+#[derive(Resource, Default)]
+struct PresetUiState {
+    selected_index: usize,
+}
 
 pub struct UiPlugin;
 
@@ -10,6 +27,7 @@ impl Plugin for UiPlugin {
         app.add_plugins(EguiPlugin {
             enable_multipass_for_primary_context: true,
         });
+        app.init_resource::<PresetUiState>();
         app.add_systems(EguiContextPass, ui_system);
     }
 }
@@ -17,7 +35,9 @@ impl Plugin for UiPlugin {
 fn ui_system(
     mut contexts: EguiContexts,
     mut boid_settings: ResMut<BoidSettings>,
-    mut grid_settings: ResMut<GridSettings>
+    mut grid_settings: ResMut<GridSettings>,
+    mut simulation_settings: ResMut<SimulationSettings>,
+    mut preset_ui: ResMut<PresetUiState>,
 ) {
     egui::Window::new("Parameters").show(contexts.ctx_mut(), |ui| {
         ui.heading("Boids Settings");
@@ -36,6 +56,8 @@ fn ui_system(
             .text("Alignment Weight"));
         ui.add(egui::Slider::new(&mut boid_settings.cohesion_weight, 0.0..=5.0)
             .text("Cohesion Weight"));
+        ui.add(egui::Slider::new(&mut boid_settings.view_angle, 0.0..=std::f32::consts::TAU)
+            .text("View Angle (rad)"));
 
         // Nouveaux contrôles pour la souris
         ui.separator();
@@ -45,9 +67,85 @@ fn ui_system(
         ui.add(egui::Slider::new(&mut boid_settings.mouse_arrival_radius, 20.0..=300.0)
             .text("Arrival Radius"));
 
+        ui.separator();
+        ui.heading("Predator / Prey");
+        ui.add(egui::Slider::new(&mut boid_settings.predator_count, 0..=NUMBER_BOIDS)
+            .text("Predator Count (restart to apply)"));
+        ui.add(egui::Slider::new(&mut boid_settings.predator_detection_radius, 50.0..=500.0)
+            .text("Predator Detection Radius"));
+        ui.add(egui::Slider::new(&mut boid_settings.predator_arrival_radius, 10.0..=150.0)
+            .text("Predator Arrival Radius"));
+        ui.add(egui::Slider::new(&mut boid_settings.flee_radius, 0.0..=300.0)
+            .text("Flee Radius"));
+        ui.add(egui::Slider::new(&mut boid_settings.flee_weight, 0.0..=5.0)
+            .text("Flee Weight"));
+
+        ui.separator();
+        ui.heading("Obstacle Avoidance");
+        ui.add(egui::Slider::new(&mut boid_settings.detection_length, 10.0..=150.0)
+            .text("Detection Length"));
+        ui.add(egui::Slider::new(&mut boid_settings.avoidance_strength, 0.0..=1000.0)
+            .text("Avoidance Strength"));
+
         ui.separator();
         ui.heading("Grid Settings");
         ui.add(egui::Slider::new(&mut grid_settings.width, 200.0..=1000.0).text("Width"));
         ui.add(egui::Slider::new(&mut grid_settings.height, 200.0..=1000.0).text("Height"));
+
+        ui.label("Boundary Mode");
+        egui::ComboBox::from_label("Mode")
+            .selected_text(boundary_mode_label(grid_settings.boundary_mode))
+            .show_ui(ui, |ui| {
+                for mode in [BoundaryMode::Repulsion, BoundaryMode::Wrap, BoundaryMode::HardClamp] {
+                    ui.selectable_value(&mut grid_settings.boundary_mode, mode, boundary_mode_label(mode));
+                }
+            });
+        ui.add(egui::Slider::new(&mut grid_settings.border_distance, 10.0..=150.0)
+            .text("Border Distance"));
+        ui.add(egui::Slider::new(&mut grid_settings.repulsion_strength, 0.0..=500.0)
+            .text("Repulsion Strength"));
+
+        ui.separator();
+        ui.heading("Simulation");
+        ui.add(egui::Slider::new(&mut simulation_settings.simulation_hz, 10.0..=120.0)
+            .text("Simulation Hz"));
+
+        ui.separator();
+        ui.heading("Presets");
+        let built_in = presets::built_in_presets();
+        egui::ComboBox::from_label("Built-in Preset")
+            .selected_text(built_in[preset_ui.selected_index].name.clone())
+            .show_ui(ui, |ui| {
+                for (index, preset) in built_in.iter().enumerate() {
+                    ui.selectable_value(&mut preset_ui.selected_index, index, &preset.name);
+                }
+            });
+        if ui.button("Apply Preset").clicked() {
+            let preset = &built_in[preset_ui.selected_index];
+            *boid_settings = preset.boid_settings.clone();
+            *grid_settings = preset.grid_settings.clone();
+        }
+
+        ui.horizontal(|ui| {
+            if ui.button("Save to File").clicked() {
+                let preset = FlockPreset {
+                    name: "Custom".to_string(),
+                    boid_settings: boid_settings.clone(),
+                    grid_settings: grid_settings.clone(),
+                };
+                if let Err(err) = presets::save_preset(&preset, presets::PRESET_PATH) {
+                    warn!("Failed to save preset: {err}");
+                }
+            }
+            if ui.button("Load from File").clicked() {
+                match presets::load_preset(presets::PRESET_PATH) {
+                    Ok(preset) => {
+                        *boid_settings = preset.boid_settings;
+                        *grid_settings = preset.grid_settings;
+                    }
+                    Err(err) => warn!("Failed to load preset: {err}"),
+                }
+            }
+        });
     });
 }
\ No newline at end of file